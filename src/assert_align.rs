@@ -0,0 +1,164 @@
+/// Asserts that types are equal in alignment.
+///
+/// This is the alignment counterpart to
+/// [`assert_size_eq!`](macro.assert_size_eq.html); it is useful when
+/// reasoning about `#[repr(C)]` FFI structs, SIMD types, or anything else
+/// placed into memory whose alignment must line up exactly.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_eq!(u32, i32, f32);
+/// ```
+///
+/// The following example fails to compile because `u8` and `u32` have
+/// different alignments:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_eq!(u8, u32);
+/// ```
+#[macro_export]
+macro_rules! assert_align_eq {
+    ($x:ty, $($xs:ty),+ $(,)?) => {
+        $(const _: () = assert!(
+            $crate::_core::mem::align_of::<$x>() == $crate::_core::mem::align_of::<$xs>()
+        );)+
+    };
+}
+
+/// Asserts that one type has a smaller alignment than another.
+///
+/// More than two types may be given, in which case each consecutive pair
+/// must satisfy the ordering.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_lt!(u8, u16, u32);
+/// ```
+///
+/// The following example fails to compile because `u32` is not less aligned
+/// than `u8`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align_lt!(u32, u8);
+/// ```
+#[macro_export]
+macro_rules! assert_align_lt {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        $crate::_assert_align_lt!($x, $y $(, $xs)*);
+    };
+}
+
+/// Recursive helper for [`assert_align_lt!`](macro.assert_align_lt.html).
+///
+/// Kept separate (and hidden) so the public macro's only arm requires at
+/// least two types; if the terminal one-type case lived in the same macro,
+/// a forgotten second type would silently match it and expand to nothing.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_align_lt {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        const _: () = assert!(
+            $crate::_core::mem::align_of::<$x>() < $crate::_core::mem::align_of::<$y>()
+        );
+        $crate::_assert_align_lt!($y $(, $xs)*);
+    };
+    ($x:ty $(,)?) => {};
+}
+
+/// Asserts that one type has an alignment no larger than another's.
+///
+/// See [`assert_align_lt!`](macro.assert_align_lt.html) for details; this
+/// macro is identical except it allows the alignments to be equal.
+///
+/// A common use is asserting a wrapper type is at least as aligned as the
+/// payload it must be cast to:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(align(8))]
+/// struct AlignedBuf([u8; 8]);
+///
+/// assert_align_le!(u64, AlignedBuf);
+/// ```
+#[macro_export]
+macro_rules! assert_align_le {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        $crate::_assert_align_le!($x, $y $(, $xs)*);
+    };
+}
+
+/// Recursive helper for [`assert_align_le!`](macro.assert_align_le.html).
+///
+/// See [`_assert_align_lt!`](macro._assert_align_lt.html) for why this is
+/// kept separate from the public macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_align_le {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        const _: () = assert!(
+            $crate::_core::mem::align_of::<$x>() <= $crate::_core::mem::align_of::<$y>()
+        );
+        $crate::_assert_align_le!($y $(, $xs)*);
+    };
+    ($x:ty $(,)?) => {};
+}
+
+/// Asserts that one type has a larger alignment than another.
+///
+/// See [`assert_align_lt!`](macro.assert_align_lt.html) for details; this
+/// macro checks the opposite ordering.
+#[macro_export]
+macro_rules! assert_align_gt {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        $crate::_assert_align_gt!($x, $y $(, $xs)*);
+    };
+}
+
+/// Recursive helper for [`assert_align_gt!`](macro.assert_align_gt.html).
+///
+/// See [`_assert_align_lt!`](macro._assert_align_lt.html) for why this is
+/// kept separate from the public macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_align_gt {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        const _: () = assert!(
+            $crate::_core::mem::align_of::<$x>() > $crate::_core::mem::align_of::<$y>()
+        );
+        $crate::_assert_align_gt!($y $(, $xs)*);
+    };
+    ($x:ty $(,)?) => {};
+}
+
+/// Asserts that one type has an alignment no smaller than another's.
+///
+/// See [`assert_align_lt!`](macro.assert_align_lt.html) for details; this
+/// macro checks the opposite ordering and allows the alignments to be equal.
+#[macro_export]
+macro_rules! assert_align_ge {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        $crate::_assert_align_ge!($x, $y $(, $xs)*);
+    };
+}
+
+/// Recursive helper for [`assert_align_ge!`](macro.assert_align_ge.html).
+///
+/// See [`_assert_align_lt!`](macro._assert_align_lt.html) for why this is
+/// kept separate from the public macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_align_ge {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        const _: () = assert!(
+            $crate::_core::mem::align_of::<$x>() >= $crate::_core::mem::align_of::<$y>()
+        );
+        $crate::_assert_align_ge!($y $(, $xs)*);
+    };
+    ($x:ty $(,)?) => {};
+}
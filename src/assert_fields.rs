@@ -0,0 +1,138 @@
+/// Asserts that the struct or enum variant has the given field(s).
+///
+/// This is useful for catching, at compile time, a refactor of an external
+/// struct or enum that silently drops or renames a field you depend on.
+///
+/// # Examples
+///
+/// Asserting that a struct has certain fields:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Foo {
+///     bar: u8,
+///     baz: u8,
+/// }
+///
+/// assert_fields!(Foo: bar, baz);
+/// ```
+///
+/// Asserting that a specific enum variant has a field, by pointing the path
+/// at the variant itself:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Square { side: f64 },
+/// }
+///
+/// assert_fields!(Shape::Circle: radius);
+/// ```
+///
+/// Asserting that a field has a specific type:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Foo {
+///     bar: u8,
+/// }
+///
+/// assert_fields!(Foo: bar: u8);
+/// ```
+///
+/// The following example fails to compile because `Foo` has no field named
+/// `nope`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Foo {
+///     bar: u8,
+/// }
+///
+/// assert_fields!(Foo: nope);
+/// ```
+///
+/// The following example fails to compile because `bar` is a `u8`, not a
+/// `u16`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Foo {
+///     bar: u8,
+/// }
+///
+/// assert_fields!(Foo: bar: u16);
+/// ```
+#[macro_export]
+macro_rules! assert_fields {
+    // Enum variant form: hand off to `_assert_fields_variant!`, which shifts
+    // path segments one at a time until it finds the one immediately before
+    // a bare `:` (the variant name), so a module-qualified enum path works
+    // the same as an unqualified one. This arm comes first so a variant path
+    // like `Shape::Circle` is never swallowed whole by the struct arms
+    // below, which would try (and fail) to use it as a type.
+    ($first:ident :: $($rest:tt)+) => {
+        $crate::_assert_fields_variant!(($first) :: $($rest)+);
+    };
+
+    ($struct:path: $field:ident: $field_ty:ty) => {
+        const _: fn($struct) = |value: $struct| {
+            #[allow(unreachable_patterns)]
+            match value {
+                $struct { $field, .. } => {
+                    let _: $field_ty = $field;
+                }
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        };
+    };
+    ($struct:path: $($field:ident),+ $(,)?) => {
+        const _: fn(&$struct) = |value: &$struct| {
+            #[allow(unreachable_patterns)]
+            match value {
+                $struct { $($field: _,)+ .. } => {}
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        };
+    };
+}
+
+/// Recursive helper for [`assert_fields!`](macro.assert_fields.html)'s enum
+/// variant form.
+///
+/// The accumulated path-so-far is kept parenthesized (rather than spliced in
+/// directly) so each step's `$($enum:ident)::+` repetition is bounded by the
+/// surrounding `(...)`; matching it unparenthesized against a trailing `::`
+/// is ambiguous, since the repetition's own separator is also `::`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_fields_variant {
+    (($($enum:ident)::+) :: $next:ident :: $($rest:tt)+) => {
+        $crate::_assert_fields_variant!(($($enum)::+ :: $next) :: $($rest)+);
+    };
+    (($($enum:ident)::+) :: $variant:ident: $field:ident: $field_ty:ty) => {
+        const _: fn($($enum)::+) = |value: $($enum)::+| {
+            #[allow(unreachable_patterns)]
+            match value {
+                $($enum)::+::$variant { $field, .. } => {
+                    let _: $field_ty = $field;
+                }
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        };
+    };
+    (($($enum:ident)::+) :: $variant:ident: $($field:ident),+ $(,)?) => {
+        const _: fn(&$($enum)::+) = |value: &$($enum)::+| {
+            #[allow(unreachable_patterns)]
+            match value {
+                $($enum)::+::$variant { $($field: _,)+ .. } => {}
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        };
+    };
+}
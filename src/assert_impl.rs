@@ -0,0 +1,229 @@
+/// Returns a [`bool`] constant indicating whether `$type` implements `$trait`.
+///
+/// This is useful for feeding implementation status into arbitrary `const`
+/// logic, such as a [`const_assert!`]-style check, without having to write a
+/// dedicated macro for every such check. [`assert_impl!`] is built on top of
+/// this primitive in order to support its `!` negation operator.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const _: () = assert!(impls!(u8: Copy));
+/// const _: () = assert!(!impls!(*const u8: Send));
+/// ```
+///
+/// [`assert_impl!`]: macro.assert_impl.html
+#[macro_export]
+macro_rules! impls {
+    ($type:ty: $trait:path) => {{
+        trait DoesNotImpl {
+            const IMPLS: bool = false;
+        }
+        impl<T> DoesNotImpl for T {}
+
+        struct Wrapper<T>($crate::_core::marker::PhantomData<T>);
+
+        #[allow(dead_code)]
+        impl<T: $trait> Wrapper<T> {
+            const IMPLS: bool = true;
+        }
+
+        <Wrapper<$type>>::IMPLS
+    }};
+}
+
+/// Asserts that the type implements all or any of the given traits.
+///
+/// Each trait may be negated by prefixing it with `!`, in which case the
+/// type is asserted to *not* implement that trait. Traits are joined with
+/// `&` to require all of them (or none of the negated ones) to hold, or with
+/// `|` to require at least one. The two separators may be mixed, in which
+/// case the chain groups from the right: `(A) & (B) | (C)` requires `A` and
+/// at least one of `B` or `C`.
+///
+/// An optional `for(...)` clause can introduce generics that `$type` is then
+/// free to reference, in which case the check must hold for *every* type the
+/// generics range over. Because of that, `!` negation isn't supported inside
+/// `for(...)`: there's no single concrete type to show doesn't implement the
+/// trait, only a statement that the trait isn't implied by the declared
+/// bounds.
+///
+/// # Examples
+///
+/// Using `for(...)` to introduce a generic type, [`Copy`] types always
+/// implement [`Clone`]:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl!(for(T: Copy) T: (Clone));
+/// ```
+///
+/// [`*const u8`] implements [`Copy`] and does not implement [`Sync`]:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl!(*const u8: (Copy) & (!Sync));
+/// ```
+///
+/// The following example fails to compile because [`u8`] does implement
+/// [`Copy`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl!(u8: (!Copy));
+/// ```
+///
+/// [`*const u8`]: https://doc.rust-lang.org/std/primitive.pointer.html
+/// [`Copy`]:     https://doc.rust-lang.org/std/marker/trait.Copy.html
+/// [`Clone`]:    https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`Sync`]:     https://doc.rust-lang.org/std/marker/trait.Sync.html
+/// [`u8`]:       https://doc.rust-lang.org/std/primitive.u8.html
+#[macro_export]
+macro_rules! assert_impl {
+    (for($($generics:tt)*) $type:ty: $($rest:tt)+) => {
+        const _: fn() = || {
+            fn __assert<$($generics)*>() {
+                struct __True;
+                struct __False;
+
+                trait __Or<Rhs> {
+                    type Output;
+                    fn __or(self, rhs: Rhs) -> Self::Output;
+                }
+                impl __Or<__False> for __False {
+                    type Output = __False;
+                    fn __or(self, _: __False) -> __False { __False }
+                }
+                impl __Or<__True> for __False {
+                    type Output = __True;
+                    fn __or(self, _: __True) -> __True { __True }
+                }
+                impl __Or<__False> for __True {
+                    type Output = __True;
+                    fn __or(self, _: __False) -> __True { __True }
+                }
+                impl __Or<__True> for __True {
+                    type Output = __True;
+                    fn __or(self, _: __True) -> __True { __True }
+                }
+
+                trait __And<Rhs> {
+                    type Output;
+                    fn __and(self, rhs: Rhs) -> Self::Output;
+                }
+                impl __And<__False> for __False {
+                    type Output = __False;
+                    fn __and(self, _: __False) -> __False { __False }
+                }
+                impl __And<__True> for __False {
+                    type Output = __False;
+                    fn __and(self, _: __True) -> __False { __False }
+                }
+                impl __And<__False> for __True {
+                    type Output = __False;
+                    fn __and(self, _: __False) -> __False { __False }
+                }
+                impl __And<__True> for __True {
+                    type Output = __True;
+                    fn __and(self, _: __True) -> __True { __True }
+                }
+
+                fn __require(_: __True) {}
+
+                __require($crate::_impls_kind_expr!(__True, __False, __Or, __And, $type: $($rest)+));
+            }
+        };
+    };
+    ($type:ty: $($rest:tt)+) => {
+        const _: () = assert!($crate::_impls_expr!($type: $($rest)+));
+    };
+}
+
+/// Expands a `(trait)`/`(!trait)` chain joined by `&` or `|` into a single
+/// `bool` expression built out of [`impls!`](macro.impls.html) calls.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _impls_expr {
+    ($type:ty: (!$trait:path)) => {
+        !$crate::impls!($type: $trait)
+    };
+    ($type:ty: ($trait:path)) => {
+        $crate::impls!($type: $trait)
+    };
+    ($type:ty: (!$trait:path) & $($rest:tt)+) => {
+        (!$crate::impls!($type: $trait)) && $crate::_impls_expr!($type: $($rest)+)
+    };
+    ($type:ty: ($trait:path) & $($rest:tt)+) => {
+        $crate::impls!($type: $trait) && $crate::_impls_expr!($type: $($rest)+)
+    };
+    ($type:ty: (!$trait:path) | $($rest:tt)+) => {
+        (!$crate::impls!($type: $trait)) || $crate::_impls_expr!($type: $($rest)+)
+    };
+    ($type:ty: ($trait:path) | $($rest:tt)+) => {
+        $crate::impls!($type: $trait) || $crate::_impls_expr!($type: $($rest)+)
+    };
+}
+
+/// Returns a `$true_ty`/`$false_ty` value indicating whether `$type`
+/// implements `$trait`.
+///
+/// This is resolved the same way [`impls!`](macro.impls.html) is (an
+/// inherent impl, bound on `$trait`, shadows a blanket trait impl that
+/// otherwise applies to every type), but the result is carried as a
+/// distinct *type* per outcome rather than a `bool`. That distinction
+/// matters inside [`assert_impl!`]'s `for(...)` arm: a `bool` computed in
+/// the body of a never-instantiated generic function is never actually
+/// read, so a `const`-based check on it would silently pass, whereas an
+/// unmet bound here surfaces as an ordinary type mismatch that the
+/// compiler catches while merely type-checking the function, without ever
+/// calling it.
+///
+/// `$true_ty` and `$false_ty` are threaded through from the call site
+/// (rather than being fixed types owned by this macro) so that callers can
+/// combine the results of several invocations, as
+/// [`_impls_kind_expr!`](macro._impls_kind_expr.html) does.
+///
+/// [`assert_impl!`]: macro.assert_impl.html
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _impls_kind {
+    ($true_ty:ident, $false_ty:ident, $type:ty: $trait:path) => {{
+        struct __Witness<__T: ?Sized>($crate::_core::marker::PhantomData<__T>);
+
+        trait __ViaDefault {
+            fn __kind(&self) -> $false_ty { $false_ty }
+        }
+        impl<__T: ?Sized> __ViaDefault for __Witness<__T> {}
+
+        impl<__T: ?Sized + $trait> __Witness<__T> {
+            fn __kind(&self) -> $true_ty { $true_ty }
+        }
+
+        __Witness::<$type>($crate::_core::marker::PhantomData).__kind()
+    }};
+}
+
+/// Expands a `(trait)` chain joined by `&` or `|` into a single
+/// `$true_ty`/`$false_ty` expression built out of
+/// [`_impls_kind!`](macro._impls_kind.html) calls, combined using the
+/// `$or_trait`/`$and_trait` passed in by the caller.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _impls_kind_expr {
+    ($true_ty:ident, $false_ty:ident, $or_trait:ident, $and_trait:ident, $type:ty: ($trait:path)) => {
+        $crate::_impls_kind!($true_ty, $false_ty, $type: $trait)
+    };
+    ($true_ty:ident, $false_ty:ident, $or_trait:ident, $and_trait:ident, $type:ty: ($trait:path) & $($rest:tt)+) => {
+        $and_trait::__and(
+            $crate::_impls_kind!($true_ty, $false_ty, $type: $trait),
+            $crate::_impls_kind_expr!($true_ty, $false_ty, $or_trait, $and_trait, $type: $($rest)+),
+        )
+    };
+    ($true_ty:ident, $false_ty:ident, $or_trait:ident, $and_trait:ident, $type:ty: ($trait:path) | $($rest:tt)+) => {
+        $or_trait::__or(
+            $crate::_impls_kind!($true_ty, $false_ty, $type: $trait),
+            $crate::_impls_kind_expr!($true_ty, $false_ty, $or_trait, $and_trait, $type: $($rest)+),
+        )
+    };
+}
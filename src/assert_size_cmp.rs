@@ -0,0 +1,295 @@
+/// Asserts that one type is smaller in size than another.
+///
+/// Unlike [`assert_size_eq!`](macro.assert_size_eq.html), this is implemented
+/// as a `const` check, so a failure points directly at the offending
+/// comparison instead of an opaque `transmute` error. More than two types may
+/// be given, in which case each consecutive pair must satisfy the ordering.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_lt!(u8, u16, u32, u64);
+/// ```
+///
+/// The following example fails to compile because `u32` is not smaller than
+/// `u8`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_lt!(u32, u8);
+/// ```
+#[macro_export]
+macro_rules! assert_size_lt {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        $crate::_assert_size_lt!($x, $y $(, $xs)*);
+    };
+}
+
+/// Recursive helper for [`assert_size_lt!`](macro.assert_size_lt.html).
+///
+/// Kept separate (and hidden) so the public macro's only arm requires at
+/// least two types; if the terminal one-type case lived in the same macro,
+/// a forgotten second type would silently match it and expand to nothing.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_size_lt {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        const _: () = assert!(
+            $crate::_core::mem::size_of::<$x>() < $crate::_core::mem::size_of::<$y>()
+        );
+        $crate::_assert_size_lt!($y $(, $xs)*);
+    };
+    ($x:ty $(,)?) => {};
+}
+
+/// Asserts that one type is no larger in size than another.
+///
+/// See [`assert_size_lt!`](macro.assert_size_lt.html) for details; this
+/// macro is identical except it allows the sizes to be equal.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_le!(u8, u16, (u8, u8), u32);
+/// ```
+///
+/// The following example fails to compile because `u32` is larger than
+/// `u8`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_le!(u32, u8);
+/// ```
+#[macro_export]
+macro_rules! assert_size_le {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        $crate::_assert_size_le!($x, $y $(, $xs)*);
+    };
+}
+
+/// Recursive helper for [`assert_size_le!`](macro.assert_size_le.html).
+///
+/// See [`_assert_size_lt!`](macro._assert_size_lt.html) for why this is
+/// kept separate from the public macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_size_le {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        const _: () = assert!(
+            $crate::_core::mem::size_of::<$x>() <= $crate::_core::mem::size_of::<$y>()
+        );
+        $crate::_assert_size_le!($y $(, $xs)*);
+    };
+    ($x:ty $(,)?) => {};
+}
+
+/// Asserts that one type is larger in size than another.
+///
+/// See [`assert_size_lt!`](macro.assert_size_lt.html) for details; this
+/// macro checks the opposite ordering.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_gt!(u64, u32, u16, u8);
+/// ```
+///
+/// The following example fails to compile because `u8` is not larger than
+/// `u32`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_gt!(u8, u32);
+/// ```
+#[macro_export]
+macro_rules! assert_size_gt {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        $crate::_assert_size_gt!($x, $y $(, $xs)*);
+    };
+}
+
+/// Recursive helper for [`assert_size_gt!`](macro.assert_size_gt.html).
+///
+/// See [`_assert_size_lt!`](macro._assert_size_lt.html) for why this is
+/// kept separate from the public macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_size_gt {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        const _: () = assert!(
+            $crate::_core::mem::size_of::<$x>() > $crate::_core::mem::size_of::<$y>()
+        );
+        $crate::_assert_size_gt!($y $(, $xs)*);
+    };
+    ($x:ty $(,)?) => {};
+}
+
+/// Asserts that one type is no smaller in size than another.
+///
+/// See [`assert_size_lt!`](macro.assert_size_lt.html) for details; this
+/// macro checks the opposite ordering and allows the sizes to be equal.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_ge!(u32, (u8, u8), u16, u8);
+/// ```
+///
+/// The following example fails to compile because `u8` is smaller than
+/// `u32`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_ge!(u8, u32);
+/// ```
+#[macro_export]
+macro_rules! assert_size_ge {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        $crate::_assert_size_ge!($x, $y $(, $xs)*);
+    };
+}
+
+/// Recursive helper for [`assert_size_ge!`](macro.assert_size_ge.html).
+///
+/// See [`_assert_size_lt!`](macro._assert_size_lt.html) for why this is
+/// kept separate from the public macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_size_ge {
+    ($x:ty, $y:ty $(, $xs:ty)* $(,)?) => {
+        const _: () = assert!(
+            $crate::_core::mem::size_of::<$x>() >= $crate::_core::mem::size_of::<$y>()
+        );
+        $crate::_assert_size_ge!($y $(, $xs)*);
+    };
+    ($x:ty $(,)?) => {};
+}
+
+/// Asserts that a value pointed to is smaller in size than another.
+///
+/// Unlike [`assert_size_lt!`](macro.assert_size_lt.html), this compares the
+/// sizes of values rather than types, so it is checked at runtime.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// fn operation(small: &u8, big: &(u32, u32)) {
+///     assert_size_lt_ptr!(small, big);
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_size_lt_ptr {
+    ($x:expr, $y:expr $(, $xs:expr)* $(,)?) => {
+        assert!(
+            $crate::_core::mem::size_of_val($x) < $crate::_core::mem::size_of_val($y)
+        );
+        assert_size_lt_ptr!($y $(, $xs)*);
+    };
+    ($x:expr $(,)?) => {};
+}
+
+/// Asserts that a value pointed to is no larger in size than another.
+///
+/// See [`assert_size_lt_ptr!`](macro.assert_size_lt_ptr.html) for details;
+/// this macro is identical except it allows the sizes to be equal.
+#[macro_export]
+macro_rules! assert_size_le_ptr {
+    ($x:expr, $y:expr $(, $xs:expr)* $(,)?) => {
+        assert!(
+            $crate::_core::mem::size_of_val($x) <= $crate::_core::mem::size_of_val($y)
+        );
+        assert_size_le_ptr!($y $(, $xs)*);
+    };
+    ($x:expr $(,)?) => {};
+}
+
+/// Asserts that a value pointed to is larger in size than another.
+///
+/// See [`assert_size_lt_ptr!`](macro.assert_size_lt_ptr.html) for details;
+/// this macro checks the opposite ordering.
+#[macro_export]
+macro_rules! assert_size_gt_ptr {
+    ($x:expr, $y:expr $(, $xs:expr)* $(,)?) => {
+        assert!(
+            $crate::_core::mem::size_of_val($x) > $crate::_core::mem::size_of_val($y)
+        );
+        assert_size_gt_ptr!($y $(, $xs)*);
+    };
+    ($x:expr $(,)?) => {};
+}
+
+/// Asserts that a value pointed to is no smaller in size than another.
+///
+/// See [`assert_size_lt_ptr!`](macro.assert_size_lt_ptr.html) for details;
+/// this macro checks the opposite ordering and allows the sizes to be equal.
+#[macro_export]
+macro_rules! assert_size_ge_ptr {
+    ($x:expr, $y:expr $(, $xs:expr)* $(,)?) => {
+        assert!(
+            $crate::_core::mem::size_of_val($x) >= $crate::_core::mem::size_of_val($y)
+        );
+        assert_size_ge_ptr!($y $(, $xs)*);
+    };
+    ($x:expr $(,)?) => {};
+}
+
+/// Asserts that a value is smaller in size than another.
+///
+/// This macro doesn't consume its arguments and thus works for
+/// non-[`Clone`](https://doc.rust-lang.org/std/clone/trait.Clone.html)able
+/// values. See [`assert_size_lt_ptr!`](macro.assert_size_lt_ptr.html) for
+/// details.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions;
+/// # fn main() {
+/// assert_size_lt_val!(0u8, 0u32);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_size_lt_val {
+    ($x:expr, $($xs:expr),+ $(,)?) => {
+        assert_size_lt_ptr!(&$x, $(&$xs),+);
+    };
+}
+
+/// Asserts that a value is no larger in size than another.
+///
+/// See [`assert_size_lt_val!`](macro.assert_size_lt_val.html) for details;
+/// this macro is identical except it allows the sizes to be equal.
+#[macro_export]
+macro_rules! assert_size_le_val {
+    ($x:expr, $($xs:expr),+ $(,)?) => {
+        assert_size_le_ptr!(&$x, $(&$xs),+);
+    };
+}
+
+/// Asserts that a value is larger in size than another.
+///
+/// See [`assert_size_lt_val!`](macro.assert_size_lt_val.html) for details;
+/// this macro checks the opposite ordering.
+#[macro_export]
+macro_rules! assert_size_gt_val {
+    ($x:expr, $($xs:expr),+ $(,)?) => {
+        assert_size_gt_ptr!(&$x, $(&$xs),+);
+    };
+}
+
+/// Asserts that a value is no smaller in size than another.
+///
+/// See [`assert_size_lt_val!`](macro.assert_size_lt_val.html) for details;
+/// this macro checks the opposite ordering and allows the sizes to be equal.
+#[macro_export]
+macro_rules! assert_size_ge_val {
+    ($x:expr, $($xs:expr),+ $(,)?) => {
+        assert_size_ge_ptr!(&$x, $(&$xs),+);
+    };
+}